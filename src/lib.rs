@@ -8,12 +8,35 @@ extern crate alloc;
 // Bring Vec into scope from the alloc crate.
 use alloc::vec::Vec;
 
-// Import Ethereum primitive types: Address (20 bytes) and U256 (256-bit unsigned integer).
-use alloy_primitives::{Address, U256};
+// Import Ethereum primitive types: Address (20 bytes), U256 (256-bit unsigned integer)
+// and FixedBytes for the 32-byte transaction identifiers used by the timelock controller.
+use alloy_primitives::{keccak256, Address, FixedBytes, U256};
 // Import macros and types for Solidity compatibility and error handling.
-use alloy_sol_types::{sol, SolError};
+use alloy_sol_types::{sol, SolCall, SolError, SolValue};
 // Import Stylus SDK modules for interacting with the EVM and blockchain environment.
-use stylus_sdk::{block, call::transfer_eth, evm, prelude::*};
+use stylus_sdk::{
+    alloy_primitives::Bytes,
+    block,
+    call::{call, transfer_eth, Call},
+    evm,
+    prelude::*,
+};
+
+// Delay bounds (in seconds) that a queued transaction's execution timestamp must fall
+// within, measured from the moment it is queued, plus the window after maturity during
+// which it stays executable. These mirror the constants of a DAO-style timelock controller.
+const MIN_DELAY: u64 = 10; // Minimum delay between queueing and the earliest execution time.
+const MAX_DELAY: u64 = 1000; // Maximum delay between queueing and the latest execution time.
+const GRACE_PERIOD: u64 = 1000; // Window after the timestamp during which execution is still allowed.
+
+// Mandatory delay between requesting a withdrawal and being able to release it, giving the
+// owner (or a monitoring backend) a window to react to a compromised key.
+const RELEASE_LOCK: u64 = 100;
+// Maximum number of outstanding withdrawal requests a single caller may hold at once.
+const MAX_REQUESTS: u64 = 1;
+
+// Delay that must elapse between initiating a recovery and being able to finalize it.
+const RECOVERY_DELAY: u64 = 1000;
 
 // Use the sol! macro to define Solidity-style events and errors for the contract.
 sol! {
@@ -22,12 +45,61 @@ sol! {
     // Event emitted when ETH is withdrawn from the contract.
     event Withdrawal(address indexed to, uint256 amount);
 
+    // Events emitted when ERC-20 tokens are deposited into or withdrawn from the wallet.
+    event TokenDeposit(address indexed token, address indexed from, uint256 amount);
+    event TokenWithdrawal(address indexed token, address indexed to, uint256 amount);
+
+    // Event emitted when a two-phase withdrawal is requested and begins its release delay.
+    event WithdrawalRequested(address indexed caller, address indexed to, uint256 amount, uint256 readyAt);
+
+    // Events emitted as a social-recovery proposal moves through its lifecycle.
+    event RecoveryInitiated(address indexed proposedOwner, address indexed initiator);
+    event RecoveryFinalized(address indexed newOwner);
+    event RecoveryCancelled();
+
+    // Events emitted by the timelock controller as a transaction moves through its lifecycle.
+    event Queue(bytes32 indexed txId, address indexed target, uint256 value, uint256 timestamp);
+    event Execute(bytes32 indexed txId, address indexed target, uint256 value, uint256 timestamp);
+    event Cancel(bytes32 indexed txId);
+
     // Custom Solidity-style errors for better error handling.
     error NotOwner();         // Thrown if a non-owner tries to call owner-only functions.
     error FundsLocked();      // Thrown if funds are still locked and withdrawal is attempted.
     error ZeroBalance();      // Thrown if withdrawal is attempted with zero balance.
     error AlreadyInitialized(); // Thrown if init is called more than once.
     error NotInitialized();   // Thrown if contract is used before initialization.
+    error LockExists(bytes32 id); // Thrown if a deposit targets an id that already holds funds.
+    error TokenTransferFailed(); // Thrown if an ERC-20 transfer/transferFrom call fails.
+    error ReleaseLocked(uint256 blockTimestamp, uint256 readyAt); // Release attempted before the delay elapsed.
+    error NoPendingWithdrawal(); // Release/cancel attempted with no pending request.
+    error TooManyRequests(); // Caller already holds the maximum number of outstanding requests.
+    error NotGuardian(); // Caller is not a registered guardian.
+    error RecoveryActive(); // A different recovery proposal is already in progress.
+    error NoActiveRecovery(); // No recovery proposal is currently in progress.
+    error AlreadyApproved(); // Guardian has already approved the current proposal.
+    error RecoveryDelayNotPassed(); // Finalize attempted before the recovery delay elapsed.
+    error RecoveryDelayPassed(); // Cancel attempted after the recovery delay elapsed.
+    error InsufficientApprovals(); // Not enough guardians have approved to finalize.
+    error InvalidSignature(); // The recovered signer is not the owner or the signature is malformed.
+    error NonceUsed(); // The supplied nonce has already been consumed.
+
+    // Timelock controller errors, mirroring the ITimeLock interface.
+    error AlreadyQueuedError(bytes32 txId);                       // The transaction id is already queued.
+    error TimestampNotInRangeError(uint256 blockTimestamp, uint256 timestamp); // Timestamp outside [now+MIN, now+MAX].
+    error NotQueuedError(bytes32 txId);                           // The transaction id is not queued.
+    error TimestampNotPassedError(uint256 blockTimestamp, uint256 timestamp);  // Execution attempted before maturity.
+    error TimestampExpiredError(uint256 blockTimestamp, uint256 expiresAt);    // Execution attempted after the grace period.
+    error TxFailedError();                                        // The low-level call reverted.
+}
+
+// Minimal ERC-20 interface used to custody tokens alongside native ETH. The call structs
+// generated here are ABI-encoded by hand and dispatched through `stylus_sdk::call`.
+sol! {
+    interface IERC20 {
+        function transferFrom(address from, address to, uint256 amount) external returns (bool);
+        function transfer(address to, uint256 amount) external returns (bool);
+        function balanceOf(address account) external view returns (uint256);
+    }
 }
 
 // Use the sol_storage! macro to define the contract's persistent storage layout.
@@ -35,7 +107,96 @@ sol_storage! {
     #[entrypoint] // Marks this struct as the main contract entry point.
     pub struct TimelockWallet {
         address owner;              // The owner of the wallet (can withdraw/extend lock).
-        uint256 unlock_timestamp;   // The timestamp after which funds can be withdrawn.
+        uint256 unlock_timestamp;   // Default unlock timestamp applied to new locks (set at init).
+        // Set of transaction ids currently queued in the timelock controller.
+        mapping(bytes32 => bool) queued;
+        // Independent named locks: each id holds its own balance and maturity.
+        mapping(bytes32 => LockData) locks;
+        // Per-token balances locked in the wallet (ERC-20 custody).
+        mapping(address => uint256) token_locked;
+        // Pending two-phase withdrawal per caller and the count they hold outstanding.
+        mapping(address => PendingWithdrawal) pending;
+        mapping(address => uint256) request_count;
+        // Social-recovery guardian set and active proposal state.
+        mapping(address => bool) guardians;     // Registered guardians.
+        uint256 guardian_count;                 // Number of registered guardians.
+        address proposed_owner;                 // Proposed new owner of the active proposal.
+        uint256 recovery_start;                 // Timestamp at which the active proposal began.
+        uint256 recovery_approvals;             // Guardians that have approved the active proposal.
+        uint256 recovery_round;                 // Monotonic id distinguishing successive proposals.
+        bool recovery_active;                   // Whether a recovery proposal is in progress.
+        mapping(address => uint256) approved_round; // Last proposal round each guardian approved.
+        // Nonces consumed by signature-authorized withdrawals (replay protection).
+        mapping(uint256 => bool) used_nonces;
+        // Linear-vesting schedule (a continuous alternative to the all-at-once cliff).
+        uint256 start_time;         // Timestamp at which vesting begins.
+        uint256 end_time;           // Timestamp at which the full amount has vested.
+        uint256 total_deposited;    // Total amount placed under the vesting schedule.
+        uint256 total_claimed;      // Amount already claimed from the vesting schedule.
+    }
+
+    // A single lock tranche: an amount of ETH and the timestamp after which it matures.
+    pub struct LockData {
+        uint256 amount;             // The ETH held by this lock.
+        uint256 unlock_timestamp;   // The timestamp after which this tranche can be withdrawn.
+    }
+
+    // A pending two-phase withdrawal awaiting its release delay.
+    pub struct PendingWithdrawal {
+        address to;                 // Recipient of the pending withdrawal.
+        uint256 amount;             // Amount to release.
+        uint256 request_time;       // Timestamp at which the request was made.
+        bool active;                // Whether a request is currently outstanding.
+    }
+}
+
+// Internal helpers that are not part of the contract's public ABI.
+impl TimelockWallet {
+    /// Interpret an ERC-20 boolean return value leniently: a missing return (common among
+    /// non-compliant tokens) is treated as success, otherwise any non-zero word is `true`.
+    fn decode_bool(data: &[u8]) -> bool {
+        data.is_empty() || data.iter().any(|&b| b != 0)
+    }
+
+    /// Clear a caller's pending withdrawal and release one slot from their outstanding count.
+    fn clear_pending(&mut self, caller: Address) {
+        let mut p = self.pending.setter(caller);
+        p.to.set(Address::ZERO);
+        p.amount.set(U256::ZERO);
+        p.request_time.set(U256::ZERO);
+        p.active.set(false);
+        let count = self.request_count.get(caller);
+        if !count.is_zero() {
+            self.request_count.setter(caller).set(count - U256::from(1));
+        }
+    }
+
+    /// Recover the address that signed `digest`, given a 65-byte `(r, s, v)` signature, by
+    /// invoking the ecrecover precompile at address 0x1.
+    fn ecrecover(&mut self, digest: FixedBytes<32>, signature: &[u8]) -> Result<Address, Vec<u8>> {
+        // Signatures must be the canonical 65-byte r||s||v layout.
+        if signature.len() != 65 {
+            return Err(InvalidSignature {}.abi_encode());
+        }
+        // Normalise the recovery id to the 27/28 form the precompile expects.
+        let v = match signature[64] {
+            0 | 27 => 27u8,
+            1 | 28 => 28u8,
+            _ => return Err(InvalidSignature {}.abi_encode()),
+        };
+        // Build the 128-byte precompile input: hash || v || r || s (each field right-aligned).
+        let mut input = [0u8; 128];
+        input[0..32].copy_from_slice(digest.as_slice());
+        input[63] = v;
+        input[64..96].copy_from_slice(&signature[0..32]);
+        input[96..128].copy_from_slice(&signature[32..64]);
+        // Call the precompile; an empty return means recovery failed.
+        let out = call(Call::new_in(self), Address::with_last_byte(1), &input)
+            .map_err(|_| InvalidSignature {}.abi_encode())?;
+        if out.len() != 32 {
+            return Err(InvalidSignature {}.abi_encode());
+        }
+        Ok(Address::from_slice(&out[12..32]))
     }
 }
 
@@ -56,25 +217,37 @@ impl TimelockWallet {
         Ok(())
     }
 
-    /// Payable function to deposit ETH into the contract.
+    /// Payable function to deposit ETH into a named lock.
+    /// The caller chooses `id`; it must not already hold funds. The new lock inherits
+    /// the default unlock timestamp set at init and can be extended per-tranche later.
     /// Emits a Deposit event. Only works after initialization.
     #[payable]
-    pub fn deposit(&self) -> Result<(), Vec<u8>> {
+    pub fn deposit(&mut self, id: FixedBytes<32>) -> Result<(), Vec<u8>> {
         // Ensure the contract is initialized.
         if self.owner.get() == Address::ZERO {
             return Err(NotInitialized {}.abi_encode());
         }
+        // Reject ids that already hold funds to avoid silently merging tranches.
+        if !self.locks.get(id).amount.get().is_zero() {
+            return Err(LockExists { id }.abi_encode());
+        }
+        // Record the new tranche, inheriting the default unlock timestamp.
+        let amount = self.vm().msg_value();
+        let default_unlock = self.unlock_timestamp.get();
+        let mut lock = self.locks.setter(id);
+        lock.amount.set(amount);
+        lock.unlock_timestamp.set(default_unlock);
         // Emit a Deposit event with sender and amount.
         evm::log(Deposit {
             from: self.vm().msg_sender(),
-            amount: self.vm().msg_value(),
+            amount,
         });
         Ok(())
     }
 
-    /// Withdraw all ETH to a specified address if the lock has expired.
-    /// Only the owner can call this, and only after unlock time.
-    pub fn withdraw(&mut self, to: Address) -> Result<(), Vec<u8>> {
+    /// Withdraw a single tranche to a specified address once its own lock has expired.
+    /// Only the owner can call this, and only after that tranche's unlock time.
+    pub fn withdraw(&mut self, id: FixedBytes<32>, to: Address) -> Result<(), Vec<u8>> {
         // Ensure the contract is initialized.
         if self.owner.get() == Address::ZERO {
             return Err(NotInitialized {}.abi_encode());
@@ -85,26 +258,30 @@ impl TimelockWallet {
         }
         // Get the current block timestamp.
         let now = U256::from(block::timestamp());
-        // Check if the lock period has expired.
-        if now < self.unlock_timestamp.get() {
+        // Check if this tranche's lock period has expired.
+        if now < self.locks.get(id).unlock_timestamp.get() {
             return Err(FundsLocked {}.abi_encode());
         }
-        // Get the contract's ETH balance.
-        let balance = self.vm().balance(self.vm().contract_address());
-        // Prevent withdrawal if balance is zero.
-        if balance.is_zero() {
+        // Read the tranche's balance.
+        let amount = self.locks.get(id).amount.get();
+        // Prevent withdrawal if the tranche is empty.
+        if amount.is_zero() {
             return Err(ZeroBalance {}.abi_encode());
         }
-        // Transfer all ETH to the specified address.
-        transfer_eth(to, balance)?;
+        // Clear the tranche before transferring (checks-effects-interactions).
+        let mut lock = self.locks.setter(id);
+        lock.amount.set(U256::ZERO);
+        lock.unlock_timestamp.set(U256::ZERO);
+        // Transfer the tranche's ETH to the specified address.
+        transfer_eth(to, amount)?;
         // Emit a Withdrawal event.
-        evm::log(Withdrawal { to, amount: balance });
+        evm::log(Withdrawal { to, amount });
         Ok(())
     }
 
-    /// Extend the lock period to a new unlock timestamp.
-    /// Only the owner can call this, and only to increase the lock time.
-    pub fn extend_lock(&mut self, new_unlock: U256) -> Result<(), Vec<u8>> {
+    /// Extend a single tranche's lock period to a new unlock timestamp.
+    /// Only the owner can call this, and only to increase that tranche's lock time.
+    pub fn extend_lock(&mut self, id: FixedBytes<32>, new_unlock: U256) -> Result<(), Vec<u8>> {
         // Ensure the contract is initialized.
         if self.owner.get() == Address::ZERO {
             return Err(NotInitialized {}.abi_encode());
@@ -113,12 +290,438 @@ impl TimelockWallet {
         if self.vm().msg_sender() != self.owner.get() {
             return Err(NotOwner {}.abi_encode());
         }
-        // New unlock time must be strictly greater than the current one.
-        if new_unlock <= self.unlock_timestamp.get() {
+        // New unlock time must be strictly greater than the tranche's current one.
+        if new_unlock <= self.locks.get(id).unlock_timestamp.get() {
+            return Err(FundsLocked {}.abi_encode());
+        }
+        // Set the new unlock timestamp on the tranche.
+        self.locks.setter(id).unlock_timestamp.set(new_unlock);
+        Ok(())
+    }
+
+    /// Return a lock's unlock timestamp (0 if the id is unknown), implementing the
+    /// Timelock-Maturity interface so off-chain valuers can read theta-decay per tranche.
+    pub fn get_maturity(&self, id: FixedBytes<32>) -> U256 {
+        self.locks.get(id).unlock_timestamp.get()
+    }
+
+    /// One-time initialiser for a linear vesting stream. Must be called after deployment.
+    /// Funds unlock continuously between `start` and `end` instead of all at once at a cliff.
+    /// The attached ETH becomes the total to vest.
+    #[payable]
+    pub fn init_vesting(&mut self, start: U256, end: U256) -> Result<(), Vec<u8>> {
+        // Prevent re-initialization: owner must be unset (Address::ZERO).
+        if self.owner.get() != Address::ZERO {
+            return Err(AlreadyInitialized {}.abi_encode());
+        }
+        // Set the owner to the caller of this function.
+        self.owner.set(self.vm().msg_sender());
+        // Record the vesting window and the total to stream out over it.
+        self.start_time.set(start);
+        self.end_time.set(end);
+        self.total_deposited.set(self.vm().msg_value());
+        Ok(())
+    }
+
+    /// Amount currently available to claim from the vesting schedule.
+    /// `vested = total_deposited * (min(now, end) - start) / (end - start)`, saturating to
+    /// `total_deposited` after `end` and to zero before `start`; `claimable = vested - total_claimed`.
+    pub fn claimable(&self) -> U256 {
+        let start = self.start_time.get();
+        let end = self.end_time.get();
+        let now = U256::from(block::timestamp());
+        // Nothing has vested before the schedule starts.
+        if now < start {
+            return U256::ZERO;
+        }
+        // Guard against division by zero: a degenerate window vests everything at `start`.
+        let vested = if end <= start || now >= end {
+            self.total_deposited.get()
+        } else {
+            self.total_deposited.get() * (now - start) / (end - start)
+        };
+        // Only the portion not yet claimed is available.
+        vested.saturating_sub(self.total_claimed.get())
+    }
+
+    /// Withdraw the currently-claimable vested amount to a specified address.
+    /// Only the owner can call this; reverts with ZeroBalance if nothing is claimable yet.
+    pub fn withdraw_vested(&mut self, to: Address) -> Result<(), Vec<u8>> {
+        // Ensure the contract is initialized.
+        if self.owner.get() == Address::ZERO {
+            return Err(NotInitialized {}.abi_encode());
+        }
+        // Only the owner can withdraw.
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(NotOwner {}.abi_encode());
+        }
+        // Compute the claimable amount and reject if nothing has vested yet.
+        let amount = self.claimable();
+        if amount.is_zero() {
+            return Err(ZeroBalance {}.abi_encode());
+        }
+        // Account for the claim before transferring (checks-effects-interactions).
+        self.total_claimed.set(self.total_claimed.get() + amount);
+        transfer_eth(to, amount)?;
+        // Emit a Withdrawal event.
+        evm::log(Withdrawal { to, amount });
+        Ok(())
+    }
+
+    /// Deposit ERC-20 tokens into the timelock by pulling them from the caller.
+    /// Calls `transferFrom(msg_sender, contract, amount)` and tracks the locked balance.
+    pub fn deposit_token(&mut self, token: Address, amount: U256) -> Result<(), Vec<u8>> {
+        // Ensure the contract is initialized.
+        if self.owner.get() == Address::ZERO {
+            return Err(NotInitialized {}.abi_encode());
+        }
+        let from = self.vm().msg_sender();
+        let contract = self.vm().contract_address();
+        // Pull the tokens from the caller into the contract.
+        let data = IERC20::transferFromCall {
+            from,
+            to: contract,
+            amount,
+        }
+        .abi_encode();
+        let returned = call(Call::new_in(self), token, &data)
+            .map_err(|_| TokenTransferFailed {}.abi_encode())?;
+        // Treat a `false` boolean return as a failed transfer.
+        if !Self::decode_bool(&returned) {
+            return Err(TokenTransferFailed {}.abi_encode());
+        }
+        // Track the per-token locked balance and announce the deposit.
+        let locked = self.token_locked.get(token) + amount;
+        self.token_locked.setter(token).set(locked);
+        evm::log(TokenDeposit {
+            token,
+            from,
+            amount,
+        });
+        Ok(())
+    }
+
+    /// Withdraw the full ERC-20 balance held by the contract to a specified address,
+    /// once the timelock has expired. Only the owner can call this.
+    pub fn withdraw_token(&mut self, token: Address, to: Address) -> Result<(), Vec<u8>> {
+        // Ensure the contract is initialized.
+        if self.owner.get() == Address::ZERO {
+            return Err(NotInitialized {}.abi_encode());
+        }
+        // Only the owner can withdraw.
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(NotOwner {}.abi_encode());
+        }
+        // Enforce the timelock before releasing any tokens.
+        let now = U256::from(block::timestamp());
+        if now < self.unlock_timestamp.get() {
             return Err(FundsLocked {}.abi_encode());
         }
-        // Set the new unlock timestamp.
-        self.unlock_timestamp.set(new_unlock);
+        // Read the contract's current token balance.
+        let contract = self.vm().contract_address();
+        let bal_data = IERC20::balanceOfCall { account: contract }.abi_encode();
+        let returned = call(Call::new_in(self), token, &bal_data)
+            .map_err(|_| TokenTransferFailed {}.abi_encode())?;
+        let balance = IERC20::balanceOfCall::abi_decode_returns(&returned, true)
+            .map_err(|_| TokenTransferFailed {}.abi_encode())?
+            ._0;
+        // Nothing to send.
+        if balance.is_zero() {
+            return Err(ZeroBalance {}.abi_encode());
+        }
+        // Clear the tracked balance before the external call (checks-effects-interactions).
+        self.token_locked.setter(token).set(U256::ZERO);
+        // Push the whole balance out.
+        let data = IERC20::transferCall { to, amount: balance }.abi_encode();
+        let returned = call(Call::new_in(self), token, &data)
+            .map_err(|_| TokenTransferFailed {}.abi_encode())?;
+        if !Self::decode_bool(&returned) {
+            return Err(TokenTransferFailed {}.abi_encode());
+        }
+        evm::log(TokenWithdrawal {
+            token,
+            to,
+            amount: balance,
+        });
+        Ok(())
+    }
+
+    /// Record a pending withdrawal that can only be released after `RELEASE_LOCK` has elapsed.
+    /// Owner-only and only once the timelock has expired. Reverts if the caller already holds
+    /// the maximum number of outstanding requests.
+    pub fn request_withdrawal(&mut self, to: Address, amount: U256) -> Result<(), Vec<u8>> {
+        // Ensure the contract is initialized.
+        if self.owner.get() == Address::ZERO {
+            return Err(NotInitialized {}.abi_encode());
+        }
+        // Only the owner can request withdrawals.
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(NotOwner {}.abi_encode());
+        }
+        // The base timelock must have expired before any release can be scheduled.
+        let now = U256::from(block::timestamp());
+        if now < self.unlock_timestamp.get() {
+            return Err(FundsLocked {}.abi_encode());
+        }
+        // Enforce the per-caller cap on outstanding requests.
+        let caller = self.vm().msg_sender();
+        if self.request_count.get(caller) >= U256::from(MAX_REQUESTS) {
+            return Err(TooManyRequests {}.abi_encode());
+        }
+        // Record the pending request and bump the caller's counter.
+        let request_time = now;
+        let mut p = self.pending.setter(caller);
+        p.to.set(to);
+        p.amount.set(amount);
+        p.request_time.set(request_time);
+        p.active.set(true);
+        let count = self.request_count.get(caller) + U256::from(1);
+        self.request_count.setter(caller).set(count);
+        evm::log(WithdrawalRequested {
+            caller,
+            to,
+            amount,
+            readyAt: request_time + U256::from(RELEASE_LOCK),
+        });
+        Ok(())
+    }
+
+    /// Release the caller's pending withdrawal once the release delay has elapsed.
+    pub fn release(&mut self) -> Result<(), Vec<u8>> {
+        // Ensure the contract is initialized.
+        if self.owner.get() == Address::ZERO {
+            return Err(NotInitialized {}.abi_encode());
+        }
+        let caller = self.vm().msg_sender();
+        // There must be an outstanding request.
+        if !self.pending.get(caller).active.get() {
+            return Err(NoPendingWithdrawal {}.abi_encode());
+        }
+        // The release delay must have elapsed.
+        let now = U256::from(block::timestamp());
+        let ready_at = self.pending.get(caller).request_time.get() + U256::from(RELEASE_LOCK);
+        if now < ready_at {
+            return Err(ReleaseLocked {
+                blockTimestamp: now,
+                readyAt: ready_at,
+            }
+            .abi_encode());
+        }
+        // Read and clear the request before transferring (checks-effects-interactions).
+        let to = self.pending.get(caller).to.get();
+        let amount = self.pending.get(caller).amount.get();
+        self.clear_pending(caller);
+        transfer_eth(to, amount)?;
+        evm::log(Withdrawal { to, amount });
+        Ok(())
+    }
+
+    /// Cancel the caller's pending withdrawal before it is released.
+    pub fn cancel_withdrawal(&mut self) -> Result<(), Vec<u8>> {
+        // Ensure the contract is initialized.
+        if self.owner.get() == Address::ZERO {
+            return Err(NotInitialized {}.abi_encode());
+        }
+        let caller = self.vm().msg_sender();
+        // There must be an outstanding request to cancel.
+        if !self.pending.get(caller).active.get() {
+            return Err(NoPendingWithdrawal {}.abi_encode());
+        }
+        self.clear_pending(caller);
+        Ok(())
+    }
+
+    /// Register a new guardian. Owner-only.
+    pub fn add_guardian(&mut self, guardian: Address) -> Result<(), Vec<u8>> {
+        // Ensure the contract is initialized.
+        if self.owner.get() == Address::ZERO {
+            return Err(NotInitialized {}.abi_encode());
+        }
+        // Only the owner can manage guardians.
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(NotOwner {}.abi_encode());
+        }
+        // Only count newly-added guardians.
+        if !self.guardians.get(guardian) {
+            self.guardians.setter(guardian).set(true);
+            self.guardian_count.set(self.guardian_count.get() + U256::from(1));
+        }
+        Ok(())
+    }
+
+    /// Remove an existing guardian. Owner-only.
+    pub fn remove_guardian(&mut self, guardian: Address) -> Result<(), Vec<u8>> {
+        // Ensure the contract is initialized.
+        if self.owner.get() == Address::ZERO {
+            return Err(NotInitialized {}.abi_encode());
+        }
+        // Only the owner can manage guardians.
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(NotOwner {}.abi_encode());
+        }
+        // Only decrement when an actual guardian is removed.
+        if self.guardians.get(guardian) {
+            self.guardians.setter(guardian).set(false);
+            self.guardian_count.set(self.guardian_count.get() - U256::from(1));
+        }
+        Ok(())
+    }
+
+    /// Initiate an ownership-recovery proposal. Callable by any guardian.
+    /// Records the proposed owner and start time and counts the initiator as the first approval.
+    /// Reverts if a different proposal is already in progress.
+    pub fn initiate_recovery(&mut self, new_owner: Address) -> Result<(), Vec<u8>> {
+        // Ensure the contract is initialized.
+        if self.owner.get() == Address::ZERO {
+            return Err(NotInitialized {}.abi_encode());
+        }
+        // Only a guardian may initiate recovery.
+        let caller = self.vm().msg_sender();
+        if !self.guardians.get(caller) {
+            return Err(NotGuardian {}.abi_encode());
+        }
+        // Reject while another proposal is active.
+        if self.recovery_active.get() {
+            return Err(RecoveryActive {}.abi_encode());
+        }
+        // Open a fresh proposal round so prior approvals do not carry over.
+        let round = self.recovery_round.get() + U256::from(1);
+        self.recovery_round.set(round);
+        self.proposed_owner.set(new_owner);
+        self.recovery_start.set(U256::from(block::timestamp()));
+        self.recovery_active.set(true);
+        self.recovery_approvals.set(U256::from(1));
+        self.approved_round.setter(caller).set(round);
+        evm::log(RecoveryInitiated {
+            proposedOwner: new_owner,
+            initiator: caller,
+        });
+        Ok(())
+    }
+
+    /// Approve the active recovery proposal. Callable by any guardian that has not yet approved.
+    pub fn support_recovery(&mut self) -> Result<(), Vec<u8>> {
+        // Ensure the contract is initialized.
+        if self.owner.get() == Address::ZERO {
+            return Err(NotInitialized {}.abi_encode());
+        }
+        // Only a guardian may approve recovery.
+        let caller = self.vm().msg_sender();
+        if !self.guardians.get(caller) {
+            return Err(NotGuardian {}.abi_encode());
+        }
+        // There must be a proposal to approve.
+        if !self.recovery_active.get() {
+            return Err(NoActiveRecovery {}.abi_encode());
+        }
+        // A guardian may only approve the current round once.
+        let round = self.recovery_round.get();
+        if self.approved_round.get(caller) == round {
+            return Err(AlreadyApproved {}.abi_encode());
+        }
+        self.approved_round.setter(caller).set(round);
+        self.recovery_approvals
+            .set(self.recovery_approvals.get() + U256::from(1));
+        Ok(())
+    }
+
+    /// Finalize recovery once a majority of guardians have approved and the delay has elapsed.
+    pub fn finalize_recovery(&mut self) -> Result<(), Vec<u8>> {
+        // Ensure the contract is initialized.
+        if self.owner.get() == Address::ZERO {
+            return Err(NotInitialized {}.abi_encode());
+        }
+        // There must be a proposal to finalize.
+        if !self.recovery_active.get() {
+            return Err(NoActiveRecovery {}.abi_encode());
+        }
+        // The recovery delay must have elapsed.
+        let now = U256::from(block::timestamp());
+        if now < self.recovery_start.get() + U256::from(RECOVERY_DELAY) {
+            return Err(RecoveryDelayNotPassed {}.abi_encode());
+        }
+        // A strict majority of guardians must have approved.
+        if self.recovery_approvals.get() * U256::from(2) <= self.guardian_count.get() {
+            return Err(InsufficientApprovals {}.abi_encode());
+        }
+        // Transfer ownership and close the proposal.
+        let new_owner = self.proposed_owner.get();
+        self.owner.set(new_owner);
+        self.recovery_active.set(false);
+        self.recovery_approvals.set(U256::ZERO);
+        self.proposed_owner.set(Address::ZERO);
+        evm::log(RecoveryFinalized { newOwner: new_owner });
+        Ok(())
+    }
+
+    /// Cancel the active recovery proposal. Callable by the current owner within the delay.
+    pub fn cancel_recovery(&mut self) -> Result<(), Vec<u8>> {
+        // Ensure the contract is initialized.
+        if self.owner.get() == Address::ZERO {
+            return Err(NotInitialized {}.abi_encode());
+        }
+        // Only the current owner can cancel.
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(NotOwner {}.abi_encode());
+        }
+        // There must be a proposal to cancel.
+        if !self.recovery_active.get() {
+            return Err(NoActiveRecovery {}.abi_encode());
+        }
+        // Cancellation is only possible while the recovery delay is still running.
+        let now = U256::from(block::timestamp());
+        if now >= self.recovery_start.get() + U256::from(RECOVERY_DELAY) {
+            return Err(RecoveryDelayPassed {}.abi_encode());
+        }
+        self.recovery_active.set(false);
+        self.recovery_approvals.set(U256::ZERO);
+        self.proposed_owner.set(Address::ZERO);
+        evm::log(RecoveryCancelled {});
+        Ok(())
+    }
+
+    /// Withdraw ETH authorized by an off-chain owner signature, enabling relayed/gasless claims.
+    /// Reconstructs the EIP-191 digest over `(contract, to, amount, nonce, chain_id)`, recovers
+    /// the signer, and proceeds only if it is the owner and the nonce is unused. The timelock
+    /// expiry still applies. Callable by anyone holding a valid signature.
+    pub fn withdraw_with_sig(
+        &mut self,
+        to: Address,
+        amount: U256,
+        nonce: U256,
+        signature: Vec<u8>,
+    ) -> Result<(), Vec<u8>> {
+        // Ensure the contract is initialized.
+        if self.owner.get() == Address::ZERO {
+            return Err(NotInitialized {}.abi_encode());
+        }
+        // The base timelock must have expired.
+        let now = U256::from(block::timestamp());
+        if now < self.unlock_timestamp.get() {
+            return Err(FundsLocked {}.abi_encode());
+        }
+        // Reject replayed nonces.
+        if self.used_nonces.get(nonce) {
+            return Err(NonceUsed {}.abi_encode());
+        }
+        // Reconstruct the signed digest: keccak256 of the ABI-encoded authorization, then the
+        // EIP-191 personal-sign prefix.
+        let contract = self.vm().contract_address();
+        let chain_id = U256::from(block::chainid());
+        let inner = keccak256(&(contract, to, amount, nonce, chain_id).abi_encode_params());
+        let mut prefixed = Vec::with_capacity(28 + 32);
+        prefixed.extend_from_slice(b"\x19Ethereum Signed Message:\n32");
+        prefixed.extend_from_slice(inner.as_slice());
+        let digest = keccak256(&prefixed);
+        // Recover the signer and require it to be the owner.
+        let signer = self.ecrecover(digest, &signature)?;
+        if signer != self.owner.get() {
+            return Err(InvalidSignature {}.abi_encode());
+        }
+        // Consume the nonce before transferring (checks-effects-interactions).
+        self.used_nonces.setter(nonce).set(true);
+        transfer_eth(to, amount)?;
+        evm::log(Withdrawal { to, amount });
         Ok(())
     }
 
@@ -131,4 +734,159 @@ impl TimelockWallet {
     pub fn unlock_time(&self) -> U256 {
         self.unlock_timestamp.get()
     }
+
+    /// Compute the deterministic id of a scheduled transaction.
+    /// Mirrors `keccak256(abi.encode(target, value, func, data, timestamp))`.
+    pub fn get_tx_id(
+        &self,
+        target: Address,
+        value: U256,
+        func: Vec<u8>,
+        data: Vec<u8>,
+        timestamp: U256,
+    ) -> FixedBytes<32> {
+        // ABI-encode the tuple exactly as Solidity's abi.encode would, treating the
+        // function signature and calldata as dynamic `bytes`.
+        let encoded = (
+            target,
+            value,
+            Bytes::from(func),
+            Bytes::from(data),
+            timestamp,
+        )
+            .abi_encode_params();
+        keccak256(&encoded)
+    }
+
+    /// Queue a transaction for later execution. Owner-only.
+    /// Reverts if the id is already queued or if `timestamp` is not within
+    /// `[now + MIN_DELAY, now + MAX_DELAY]`.
+    pub fn queue(
+        &mut self,
+        target: Address,
+        value: U256,
+        func: Vec<u8>,
+        data: Vec<u8>,
+        timestamp: U256,
+    ) -> Result<FixedBytes<32>, Vec<u8>> {
+        // Ensure the contract is initialized.
+        if self.owner.get() == Address::ZERO {
+            return Err(NotInitialized {}.abi_encode());
+        }
+        // Only the owner can queue transactions.
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(NotOwner {}.abi_encode());
+        }
+        // Derive the transaction id and reject duplicates.
+        let tx_id = self.get_tx_id(target, value, func.clone(), data.clone(), timestamp);
+        if self.queued.get(tx_id) {
+            return Err(AlreadyQueuedError { txId: tx_id }.abi_encode());
+        }
+        // The execution timestamp must sit inside the permitted delay window.
+        let now = U256::from(block::timestamp());
+        let min = now + U256::from(MIN_DELAY);
+        let max = now + U256::from(MAX_DELAY);
+        if timestamp < min || timestamp > max {
+            return Err(TimestampNotInRangeError {
+                blockTimestamp: now,
+                timestamp,
+            }
+            .abi_encode());
+        }
+        // Mark the id as queued and announce it.
+        self.queued.setter(tx_id).set(true);
+        evm::log(Queue {
+            txId: tx_id,
+            target,
+            value,
+            timestamp,
+        });
+        Ok(tx_id)
+    }
+
+    /// Execute a previously queued transaction once its timestamp has matured. Owner-only.
+    /// Performs the low-level call with `value` forwarded and clears the queued id.
+    pub fn execute(
+        &mut self,
+        target: Address,
+        value: U256,
+        func: Vec<u8>,
+        data: Vec<u8>,
+        timestamp: U256,
+    ) -> Result<Vec<u8>, Vec<u8>> {
+        // Ensure the contract is initialized.
+        if self.owner.get() == Address::ZERO {
+            return Err(NotInitialized {}.abi_encode());
+        }
+        // Only the owner can execute transactions.
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(NotOwner {}.abi_encode());
+        }
+        // The transaction must currently be queued.
+        let tx_id = self.get_tx_id(target, value, func.clone(), data.clone(), timestamp);
+        if !self.queued.get(tx_id) {
+            return Err(NotQueuedError { txId: tx_id }.abi_encode());
+        }
+        // Enforce the maturity and grace-period window.
+        let now = U256::from(block::timestamp());
+        if now < timestamp {
+            return Err(TimestampNotPassedError {
+                blockTimestamp: now,
+                timestamp,
+            }
+            .abi_encode());
+        }
+        let expires_at = timestamp + U256::from(GRACE_PERIOD);
+        if now > expires_at {
+            return Err(TimestampExpiredError {
+                blockTimestamp: now,
+                expiresAt: expires_at,
+            }
+            .abi_encode());
+        }
+        // Clear the queued flag before making the external call (checks-effects-interactions).
+        self.queued.setter(tx_id).set(false);
+        // Build the calldata: the 4-byte selector (keccak256 of the signature) then the data.
+        let mut calldata = Vec::with_capacity(4 + data.len());
+        calldata.extend_from_slice(&keccak256(&func)[..4]);
+        calldata.extend_from_slice(&data);
+        // Perform the low-level call, forwarding `value`.
+        let result = call(Call::new_in(self).value(value), target, &calldata)
+            .map_err(|_| TxFailedError {}.abi_encode())?;
+        evm::log(Execute {
+            txId: tx_id,
+            target,
+            value,
+            timestamp,
+        });
+        Ok(result)
+    }
+
+    /// Cancel a queued transaction without executing it. Owner-only.
+    pub fn cancel(
+        &mut self,
+        target: Address,
+        value: U256,
+        func: Vec<u8>,
+        data: Vec<u8>,
+        timestamp: U256,
+    ) -> Result<(), Vec<u8>> {
+        // Ensure the contract is initialized.
+        if self.owner.get() == Address::ZERO {
+            return Err(NotInitialized {}.abi_encode());
+        }
+        // Only the owner can cancel transactions.
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(NotOwner {}.abi_encode());
+        }
+        // The transaction must currently be queued.
+        let tx_id = self.get_tx_id(target, value, func, data, timestamp);
+        if !self.queued.get(tx_id) {
+            return Err(NotQueuedError { txId: tx_id }.abi_encode());
+        }
+        // Clear the queued flag and announce the cancellation.
+        self.queued.setter(tx_id).set(false);
+        evm::log(Cancel { txId: tx_id });
+        Ok(())
+    }
 }